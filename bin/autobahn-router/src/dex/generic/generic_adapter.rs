@@ -83,7 +83,12 @@ pub async fn build_dex_internal(
         DexSubscriptionMode::Mixed(m) => DexSubscriptionMode::Mixed(m),
     };
 
-    info!("Dex {} will subscribe to {}", dex.name(), subscription_mode);
+    info!(
+        "Dex {} (version {}) will subscribe to {}",
+        dex.name(),
+        dex.dex_version(),
+        subscription_mode
+    );
 
     Ok(Dex {
         name: dex.name(),
@@ -173,6 +173,13 @@ pub trait DexInterface: Sync + Send {
 
     fn name(&self) -> String;
 
+    /// Semver-like identifier of the adapter and the account layouts it
+    /// understands, logged by the router when the adapter is loaded.
+    /// Adapters that don't track a version report "0.0.0".
+    fn dex_version(&self) -> &'static str {
+        "0.0.0"
+    }
+
     /// Defines the kind of grpc/quic subscription that should be established
     /// to the RPC/Validator to keep this adapter updated. Also defines the
     /// accounts included in a snapshot for simulation tests.